@@ -0,0 +1,21 @@
+use smallvec::smallvec;
+
+use crate::{access::*, Borrows, ComponentBorrow};
+
+/// Marker type granting unrestricted access to every component in the world.
+///
+/// Used as the `T` parameter of a [`crate::SubWorldRaw`] for systems that
+/// need to touch arbitrary components without enumerating every type up
+/// front; `borrows` reports a wildcard `&mut World` access, so it conflicts
+/// with every other borrow.
+pub struct AllAccess;
+
+impl ComponentBorrow for AllAccess {
+    fn borrows() -> Borrows {
+        smallvec![Access::new::<&mut hecs::World>()]
+    }
+
+    fn has<U: IntoAccess>() -> bool {
+        true
+    }
+}
@@ -0,0 +1,16 @@
+use smallvec::SmallVec;
+
+use crate::{access::*, Borrows};
+
+/// Returns every [`Access`] in `a` that conflicts with an access in `b`.
+///
+/// Two accesses to the same component conflict if either side requests
+/// `&mut`; the global `&World`/`&mut World` access carried by whole-world
+/// borrows (see [`crate::SubWorldRaw`] and [`crate::AllAccess`]) conflicts
+/// with everything.
+pub fn conflicts_with(a: &Borrows, b: &Borrows) -> SmallVec<[Access; 8]> {
+    a.iter()
+        .filter(|l| b.iter().any(|r| l.conflicts(r)))
+        .cloned()
+        .collect()
+}
@@ -4,10 +4,14 @@
 //! Not all items are re-exported in the crate because not all are necessary for
 //! basic usage. The traits can still be accessed and allows for custom
 //! accessors for systems.
+mod all_access;
 mod borrow;
 mod component_borrow;
+mod conflict;
 mod into_borrow;
 
+pub use all_access::*;
 pub use borrow::*;
 pub use component_borrow::*;
+pub use conflict::*;
 pub use into_borrow::*;
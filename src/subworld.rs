@@ -1,9 +1,16 @@
 use atomic_refcell::AtomicRef;
 use smallvec::smallvec;
-use std::{any::type_name, marker::PhantomData, ops::Deref};
+use std::{
+    any::type_name,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
 
-use crate::{access::*, Borrows, ComponentBorrow, Context, ContextBorrow, Error, Result, View};
-use hecs::{Component, Entity, Query, QueryBorrow, QueryOne, World};
+use crate::{
+    access::*, conflicts_with, Borrows, ComponentBorrow, Context, ContextBorrow, Error, Result,
+    Subset, View,
+};
+use hecs::{Component, Entity, Or, Query, QueryBorrow, QueryOne, Satisfies, With, Without, World};
 
 /// Type alias for a subworld referencing the world by an atomic ref. Most
 /// common for schedules
@@ -16,6 +23,44 @@ pub struct SubWorldRaw<A, T> {
     marker: PhantomData<T>,
 }
 
+/// A cached query created by [`SubWorldRaw::prepare`], carrying hecs's own
+/// archetype/fetch cache so repeated executions skip the archetype scan.
+///
+/// The subset check against the originating subworld's access, `T`, is
+/// performed once, up front, when the handle is created. `T` is then kept as
+/// part of the handle's type so [`Self::query`] can only ever be driven by a
+/// subworld with that same declared access, and re-checked on every call as
+/// a cheap belt-and-suspenders guard.
+pub struct PreparedQuery<Q: Query, T> {
+    inner: hecs::PreparedQuery<Q>,
+    marker: PhantomData<T>,
+}
+
+impl<Q: Query + Subset, T: ComponentBorrow> PreparedQuery<Q, T> {
+    fn new() -> Self {
+        Self {
+            inner: hecs::PreparedQuery::default(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Executes the prepared query against `subworld`, reusing the cached
+    /// archetype state where the world's archetypes haven't changed since
+    /// the last call.
+    /// # Panics
+    /// Panics if the query items are not a compatible subset of `subworld`.
+    pub fn query<'w, A: Deref<Target = World>>(
+        &'w mut self,
+        subworld: &'w SubWorldRaw<A, T>,
+    ) -> hecs::PreparedQueryBorrow<'w, Q> {
+        if !subworld.has_all::<Q>() {
+            panic!("Attempt to execute query on incompatible subworld")
+        }
+
+        self.inner.query(&subworld.world)
+    }
+}
+
 impl<A, T> SubWorldRaw<A, T> {
     /// Splits the world into a subworld. No borrow checking is performed so may
     /// fail during query unless guarded otherwise.
@@ -38,6 +83,13 @@ impl<A: Deref<Target = World>, T: ComponentBorrow> SubWorldRaw<A, T> {
         U::is_subset::<T>()
     }
 
+    /// Returns true if `U`'s declared borrows don't conflict with this
+    /// subworld's own, so a system or accessor declared over `U` could
+    /// safely run alongside one holding this subworld.
+    pub fn compatible_with<U: ComponentBorrow>(&self) -> bool {
+        conflicts_with(&T::borrows(), &U::borrows()).is_empty()
+    }
+
     /// Query the subworld.
     /// # Panics
     /// Panics if the query items are not a compatible subset of the subworld.
@@ -77,6 +129,27 @@ impl<A: Deref<Target = World>, T: ComponentBorrow> SubWorldRaw<A, T> {
             .map_err(|_| Error::NoSuchEntity(entity))
     }
 
+    /// Prepares a reusable, cached query against the subworld.
+    ///
+    /// This is equivalent to hecs's own [`hecs::PreparedQuery`]: the
+    /// returned handle caches the matched archetypes and fetch state across
+    /// calls, skipping the archetype scan `query`/`try_query` redo on every
+    /// call. The cache is rebuilt transparently whenever the world's
+    /// archetypes change, so it stays correct across spawns and despawns.
+    ///
+    /// # Errors
+    /// Fails if the query items are not a compatible subset of the subworld.
+    pub fn prepare<Q: Query + Subset>(&self) -> Result<PreparedQuery<Q, T>> {
+        if !self.has_all::<Q>() {
+            return Err(Error::IncompatibleSubworld {
+                subworld: T::borrows(),
+                query: Q::borrows(),
+            });
+        }
+
+        Ok(PreparedQuery::new())
+    }
+
     /// Get a single component from the world.
     ///
     /// If a mutable borrow is desired, use [`Self::query_one`] since the world is
@@ -93,6 +166,95 @@ impl<A: Deref<Target = World>, T: ComponentBorrow> SubWorldRaw<A, T> {
 
         self.world.get(entity).map_err(|e| e.into())
     }
+
+    /// Get a single component mutably from the world, runtime-checked via
+    /// hecs's own borrow counters rather than requiring an exclusive
+    /// `&mut World`.
+    ///
+    /// Wraps the hecs::NoSuchEntity error and provides the entity id
+    pub fn get_mut<C: Component>(&self, entity: Entity) -> Result<hecs::RefMut<C>> {
+        if !self.has::<&mut C>() {
+            return Err(Error::IncompatibleSubworld {
+                subworld: T::borrows(),
+                query: smallvec![Access::new::<&mut C>()],
+            });
+        }
+
+        self.world.get_mut(entity).map_err(|e| e.into())
+    }
+
+    /// Splits this subworld into two subworlds scoped to `T1` and `T2`, so
+    /// two systems can mutate non-overlapping component sets in parallel.
+    ///
+    /// # Errors
+    /// Returns `Error::IncompatibleSubworld` if `T1` or `T2` isn't a subset
+    /// of this subworld's own declared access, or if `T1` and `T2` overlap
+    /// in a component where either side requests `&mut`.
+    pub fn split_disjoint<'w, T1, T2>(&'w self) -> Result<(SubWorldRef<'w, T1>, SubWorldRef<'w, T2>)>
+    where
+        T1: ComponentBorrow + Subset,
+        T2: ComponentBorrow + Subset,
+    {
+        if !self.has_all::<T1>() {
+            return Err(Error::IncompatibleSubworld {
+                subworld: T::borrows(),
+                query: T1::borrows(),
+            });
+        }
+
+        if !self.has_all::<T2>() {
+            return Err(Error::IncompatibleSubworld {
+                subworld: T::borrows(),
+                query: T2::borrows(),
+            });
+        }
+
+        let a = T1::borrows();
+        let b = T2::borrows();
+
+        let conflicts = conflicts_with(&a, &b);
+        if !conflicts.is_empty() {
+            return Err(Error::IncompatibleSubworld {
+                subworld: a,
+                query: conflicts,
+            });
+        }
+
+        let world: &World = &self.world;
+        Ok((SubWorldRaw::new(world), SubWorldRaw::new(world)))
+    }
+}
+
+impl<A: DerefMut<Target = World>, T: ComponentBorrow> SubWorldRaw<A, T> {
+    /// Query the subworld mutably, skipping hecs's runtime borrow checks in
+    /// favour of the static guarantee that `&mut self` already gives.
+    /// # Panics
+    /// Panics if the query items are not a compatible subset of the subworld.
+    pub fn query_mut<'w, Q: Query + Subset>(&'w mut self) -> hecs::QueryMut<'w, Q> {
+        if !self.has_all::<Q>() {
+            panic!("Attempt to execute query on incompatible subworld")
+        }
+
+        self.world.query_mut::<Q>()
+    }
+
+    /// Query the subworld mutably for a single entity.
+    /// Wraps the hecs::NoSuchEntity error and provides the entity id
+    pub fn query_one_mut<'w, Q: Query + Subset>(
+        &'w mut self,
+        entity: Entity,
+    ) -> Result<<Q::Fetch as hecs::Fetch<'w>>::Item> {
+        if !self.has_all::<Q>() {
+            return Err(Error::IncompatibleSubworld {
+                subworld: T::borrows(),
+                query: Q::borrows(),
+            });
+        }
+
+        self.world
+            .query_one_mut::<Q>(entity)
+            .map_err(|_| Error::NoSuchEntity(entity))
+    }
 }
 
 impl<'a, A, T> View<'a> for SubWorldRaw<A, T>
@@ -145,3 +307,153 @@ impl<A, T: ComponentBorrow> ComponentBorrow for SubWorldRaw<A, T> {
         T::has::<U>()
     }
 }
+
+// hecs's filter wrappers don't carry data themselves, but still need to be
+// reflected in the required-access set of a query: `With`/`Without` read the
+// filtered column to test for presence, `Or` may touch either branch, and
+// `Satisfies` only ever peeks at a component without yielding it. The filter
+// part of each only ever adds read access; `With`/`Without`/`Or` forward the
+// wrapped queries' own accesses unchanged, `&mut` included.
+impl<C: Component, Q: Subset> Subset for With<C, Q> {
+    fn is_subset<T: ComponentBorrow>() -> bool {
+        T::has::<&C>() && Q::is_subset::<T>()
+    }
+
+    fn borrows() -> Borrows {
+        let mut borrows = Q::borrows();
+        borrows.push(Access::new::<&C>());
+        borrows
+    }
+}
+
+impl<C: Component, Q: Subset> Subset for Without<C, Q> {
+    fn is_subset<T: ComponentBorrow>() -> bool {
+        T::has::<&C>() && Q::is_subset::<T>()
+    }
+
+    fn borrows() -> Borrows {
+        let mut borrows = Q::borrows();
+        borrows.push(Access::new::<&C>());
+        borrows
+    }
+}
+
+impl<L: Subset, R: Subset> Subset for Or<L, R> {
+    fn is_subset<T: ComponentBorrow>() -> bool {
+        L::is_subset::<T>() && R::is_subset::<T>()
+    }
+
+    fn borrows() -> Borrows {
+        let mut borrows = L::borrows();
+        borrows.extend(R::borrows());
+        borrows
+    }
+}
+
+// `Satisfies<Q>` only tests whether `Q` would match, it never actually
+// borrows `Q`'s columns, so unlike `With`/`Without` it must downgrade every
+// access in `Q` to a read, even where `Q` itself is `&mut C` — forwarding
+// `Q::borrows()` verbatim would wrongly demand write access.
+impl<Q: Subset> Subset for Satisfies<Q> {
+    fn is_subset<T: ComponentBorrow>() -> bool {
+        let available = T::borrows();
+        Self::borrows()
+            .iter()
+            .all(|required| available.iter().any(|a| a.satisfies(required)))
+    }
+
+    fn borrows() -> Borrows {
+        Q::borrows().into_iter().map(Access::as_read).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AllAccess;
+
+    #[derive(Debug, PartialEq)]
+    struct Position(f32);
+    #[derive(Debug, PartialEq)]
+    struct Velocity(f32);
+
+    #[test]
+    fn satisfies_does_not_require_write_access() {
+        let mut world = World::new();
+        world.spawn((Position(0.0),));
+
+        let subworld: SubWorldRef<(&Position,)> = SubWorldRaw::new(&world);
+
+        assert!(subworld.has_all::<Satisfies<&mut Position>>());
+        assert!(subworld.has_all::<Satisfies<(&Position, &mut Position)>>());
+    }
+
+    #[test]
+    fn prepare_rejects_incompatible_query() {
+        let mut world = World::new();
+        world.spawn((Position(0.0),));
+
+        let subworld: SubWorldRef<(&Position,)> = SubWorldRaw::new(&world);
+
+        assert!(subworld.prepare::<(&Velocity,)>().is_err());
+        assert!(subworld.prepare::<(&Position,)>().is_ok());
+    }
+
+    #[test]
+    fn all_access_has_any_component() {
+        assert!(AllAccess::has::<&Position>());
+        assert!(AllAccess::has::<&mut Velocity>());
+    }
+
+    #[test]
+    fn compatible_with_detects_shared_mutable_component() {
+        let mut world = World::new();
+        world.spawn((Position(0.0), Velocity(0.0)));
+
+        let subworld: SubWorldRef<(&mut Position,)> = SubWorldRaw::new(&world);
+
+        assert!(!subworld.compatible_with::<(&mut Position,)>());
+        assert!(subworld.compatible_with::<(&Velocity,)>());
+    }
+
+    #[test]
+    fn split_disjoint_rejects_overlapping_mut() {
+        let mut world = World::new();
+        world.spawn((Position(0.0), Velocity(0.0)));
+
+        let subworld: SubWorldRef<(&mut Position, &mut Velocity)> = SubWorldRaw::new(&world);
+
+        assert!(subworld
+            .split_disjoint::<(&mut Position,), (&mut Position,)>()
+            .is_err());
+    }
+
+    #[test]
+    fn split_disjoint_rejects_access_outside_subworld() {
+        let mut world = World::new();
+        world.spawn((Position(0.0), Velocity(0.0)));
+
+        let subworld: SubWorldRef<(&Position,)> = SubWorldRaw::new(&world);
+
+        assert!(subworld
+            .split_disjoint::<(&mut Position,), (&mut Velocity,)>()
+            .is_err());
+    }
+
+    #[test]
+    fn split_disjoint_allows_parallel_mutation() {
+        let mut world = World::new();
+        let entity = world.spawn((Position(0.0), Velocity(0.0)));
+
+        let subworld: SubWorldRef<(&mut Position, &mut Velocity)> = SubWorldRaw::new(&world);
+        let (positions, velocities) = subworld
+            .split_disjoint::<(&mut Position,), (&mut Velocity,)>()
+            .unwrap();
+
+        *positions.get_mut::<Position>(entity).unwrap() = Position(1.0);
+        *velocities.get_mut::<Velocity>(entity).unwrap() = Velocity(2.0);
+
+        assert_eq!(*world.get::<Position>(entity).unwrap(), Position(1.0));
+        assert_eq!(*world.get::<Velocity>(entity).unwrap(), Velocity(2.0));
+    }
+}